@@ -10,6 +10,7 @@ use std::{
     pin::{pin, Pin},
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use axum_core::{body::Body, extract::Request, response::Response};
@@ -20,6 +21,9 @@ use hyper_util::{
     server::conn::auto::Builder,
 };
 use pin_project_lite::pin_project;
+use tokio::io::{AsyncRead, AsyncWrite};
+#[cfg(unix)]
+use tokio::net::{unix::UCred, UnixListener, UnixStream};
 use tokio::{
     net::{TcpListener, TcpStream},
     sync::watch,
@@ -84,6 +88,24 @@ use tower_service::Service;
 /// See also [`HandlerWithoutStateExt::into_make_service_with_connect_info`] and
 /// [`HandlerService::into_make_service_with_connect_info`].
 ///
+/// # Listening on multiple transports
+///
+/// `serve` is generic over the [`Listener`] trait, so it isn't limited to
+/// [`tokio::net::TcpListener`]. Anything that knows how to accept a stream and report a remote
+/// address works, including [`tokio::net::UnixListener`]:
+///
+/// ```
+/// use axum::{Router, routing::get};
+///
+/// # async {
+/// let router = Router::new().route("/", get(|| async { "Hello, World!" }));
+///
+/// let path = "/tmp/axum-example.sock";
+/// let listener = tokio::net::UnixListener::bind(path).unwrap();
+/// axum::serve(listener, router).await.unwrap();
+/// # };
+/// ```
+///
 /// [`Router`]: crate::Router
 /// [`Router::into_make_service_with_connect_info`]: crate::Router::into_make_service_with_connect_info
 /// [`MethodRouter`]: crate::routing::MethodRouter
@@ -92,69 +114,190 @@ use tower_service::Service;
 /// [`HandlerWithoutStateExt::into_make_service_with_connect_info`]: crate::handler::HandlerWithoutStateExt::into_make_service_with_connect_info
 /// [`HandlerService::into_make_service_with_connect_info`]: crate::handler::HandlerService::into_make_service_with_connect_info
 #[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
-pub fn serve<M, S>(tcp_listener: TcpListener, make_service: M) -> Serve<M, S>
+pub fn serve<L, M, S>(listener: L, make_service: M) -> Serve<L, M, S, TokioExecutor>
+where
+    L: Listener,
+    M: for<'a> Service<IncomingStream<'a, L>, Error = Infallible, Response = S>,
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    Serve {
+        listener,
+        make_service,
+        builder: Builder::new(TokioExecutor::new()),
+        on_error: Arc::new(default_on_error),
+        spawner: Arc::new(tokio_spawner),
+        _marker: PhantomData,
+    }
+}
+
+/// Like [`serve`], but drive connections with a custom executor and task spawner instead of the
+/// ambient Tokio runtime's [`TokioExecutor`] and [`tokio::spawn`].
+///
+/// `executor` drives HTTP/2 concurrency within a single connection (see
+/// [`hyper::rt::Executor`]); `spawn` is called once per accepted connection with the future that
+/// serves it, and decides how that future gets run (e.g. on a `LocalSet`, a thread-per-core pool,
+/// or anywhere other than `tokio::spawn`'s default executor).
+///
+/// The executor is fixed up front, before any [`Serve::http_builder`] configuration is applied,
+/// so there's no ordering footgun between the two: there's no later step that could rebuild the
+/// [`hyper_util::server::conn::auto::Builder`] out from under you.
+///
+/// ```
+/// use axum::{Router, routing::get};
+/// use hyper_util::rt::TokioExecutor;
+///
+/// # async {
+/// let router = Router::new().route("/", get(|| async { "Hello, World!" }));
+///
+/// let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+/// axum::serve::serve_with_executor(listener, router, TokioExecutor::new(), |fut| {
+///     tokio::spawn(fut);
+/// })
+/// .await
+/// .unwrap();
+/// # };
+/// ```
+#[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
+pub fn serve_with_executor<L, M, S, E, Sp>(
+    listener: L,
+    make_service: M,
+    executor: E,
+    spawn: Sp,
+) -> Serve<L, M, S, E>
 where
-    M: for<'a> Service<IncomingStream<'a>, Error = Infallible, Response = S>,
+    L: Listener,
+    M: for<'a> Service<IncomingStream<'a, L>, Error = Infallible, Response = S>,
     S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
     S::Future: Send,
+    Sp: Fn(futures_util::future::BoxFuture<'static, ()>) + Send + Sync + 'static,
 {
     Serve {
-        tcp_listener,
+        listener,
         make_service,
+        builder: Builder::new(executor),
+        on_error: Arc::new(default_on_error),
+        spawner: Arc::new(spawn),
         _marker: PhantomData,
     }
 }
 
 /// Future returned by [`serve`].
+///
+/// `E` is the [`hyper::rt::Executor`] used to drive HTTP/2 tasks (e.g. concurrent streams) and
+/// defaults to [`TokioExecutor`]; to use a different one, build with [`serve_with_executor`]
+/// instead of [`serve`].
 #[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
-pub struct Serve<M, S> {
-    tcp_listener: TcpListener,
+pub struct Serve<L, M, S, E = TokioExecutor> {
+    listener: L,
     make_service: M,
+    builder: Builder<E>,
+    on_error: OnError,
+    spawner: Spawner,
     _marker: PhantomData<S>,
 }
 
 #[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
-impl<M, S> Serve<M, S> {
+impl<L, M, S, E> Serve<L, M, S, E>
+where
+    L: Listener,
+{
     /// TODO
-    pub fn with_graceful_shutdown<F>(self, signal: F) -> WithGracefulShutdown<M, S, F>
+    pub fn with_graceful_shutdown<F>(self, signal: F) -> WithGracefulShutdown<L, M, S, F, E>
     where
         F: Future<Output = ()> + Send + 'static,
     {
         WithGracefulShutdown {
-            tcp_listener: self.tcp_listener,
+            listener: self.listener,
             make_service: self.make_service,
+            builder: self.builder,
+            on_error: self.on_error,
+            spawner: self.spawner,
             signal,
+            deadline: None,
             _marker: PhantomData,
         }
     }
+
+    /// Returns the local address this listener is bound to.
+    pub fn local_addr(&self) -> io::Result<L::Addr> {
+        self.listener.local_addr()
+    }
+
+    /// Configure the underlying [`hyper_util::server::conn::auto::Builder`], e.g. to set HTTP/2
+    /// keep-alive intervals, the maximum number of concurrent streams, header read timeouts, or
+    /// HTTP/1 half-close behavior.
+    ///
+    /// ```
+    /// use axum::{Router, routing::get};
+    /// use std::time::Duration;
+    ///
+    /// # async {
+    /// let router = Router::new().route("/", get(|| async { "Hello, World!" }));
+    ///
+    /// let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+    /// axum::serve(listener, router)
+    ///     .http_builder(|builder| {
+    ///         builder.http2().keep_alive_interval(Duration::from_secs(20));
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    /// # };
+    /// ```
+    pub fn http_builder(mut self, f: impl FnOnce(&mut Builder<E>)) -> Self {
+        f(&mut self.builder);
+        self
+    }
+
+    /// Register a callback invoked whenever a connection fails to serve.
+    ///
+    /// By default such errors are only logged at `trace` level, which makes diagnosing
+    /// protocol errors in production difficult. Use this to wire in your own metrics or logging.
+    ///
+    /// Errors from accepting connections (including TLS handshake failures) are handled inside
+    /// the [`Listener`] impl and are not passed to this callback.
+    pub fn on_error<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(ServeError) + Send + Sync + 'static,
+    {
+        self.on_error = Arc::new(callback);
+        self
+    }
 }
 
 #[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
-impl<M, S> Debug for Serve<M, S>
+impl<L, M, S, E> Debug for Serve<L, M, S, E>
 where
+    L: Debug + 'static,
     M: Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Self {
-            tcp_listener,
+            listener,
             make_service,
+            builder: _,
+            on_error: _,
+            spawner: _,
             _marker: _,
         } = self;
 
         f.debug_struct("Serve")
-            .field("tcp_listener", tcp_listener)
+            .field("listener", listener)
             .field("make_service", make_service)
             .finish()
     }
 }
 
 #[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
-impl<M, S> IntoFuture for Serve<M, S>
+impl<L, M, S, E> IntoFuture for Serve<L, M, S, E>
 where
-    M: for<'a> Service<IncomingStream<'a>, Error = Infallible, Response = S> + Send + 'static,
-    for<'a> <M as Service<IncomingStream<'a>>>::Future: Send,
+    L: Listener,
+    L::Addr: Debug + Clone,
+    M: for<'a> Service<IncomingStream<'a, L>, Error = Infallible, Response = S> + Send + 'static,
+    for<'a> <M as Service<IncomingStream<'a, L>>>::Future: Send,
     S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
     S::Future: Send,
+    E: hyper::rt::Executor<futures_util::future::BoxFuture<'static, ()>> + Clone + Send + Sync + 'static,
 {
     type Output = io::Result<()>;
     type IntoFuture = private::ServeFuture;
@@ -162,14 +305,18 @@ where
     fn into_future(self) -> Self::IntoFuture {
         private::ServeFuture(Box::pin(async move {
             let Self {
-                tcp_listener,
+                mut listener,
                 mut make_service,
+                builder,
+                on_error,
+                spawner,
                 _marker: _,
             } = self;
 
             loop {
-                let (tcp_stream, remote_addr) = tcp_listener.accept().await?;
-                let tcp_stream = TokioIo::new(tcp_stream);
+                let (io, remote_addr) = listener.accept().await;
+                let io = TokioIo::new(io);
+                let remote_addr_for_error = remote_addr.clone();
 
                 poll_fn(|cx| make_service.poll_ready(cx))
                     .await
@@ -177,7 +324,7 @@ where
 
                 let tower_service = make_service
                     .call(IncomingStream {
-                        tcp_stream: &tcp_stream,
+                        io: &io,
                         remote_addr,
                     })
                     .await
@@ -187,22 +334,20 @@ where
                     service: tower_service,
                 };
 
-                tokio::spawn(async move {
-                    match Builder::new(TokioExecutor::new())
+                let builder = builder.clone();
+                let on_error = Arc::clone(&on_error);
+                spawner(Box::pin(async move {
+                    if let Err(err) = builder
                         // upgrades needed for websockets
-                        .serve_connection_with_upgrades(tcp_stream, hyper_service)
+                        .serve_connection_with_upgrades(io, hyper_service)
                         .await
                     {
-                        Ok(()) => {}
-                        Err(_err) => {
-                            // This error only appears when the client doesn't send a request and
-                            // terminate the connection.
-                            //
-                            // If client sends one request then terminate connection whenever, it doesn't
-                            // appear.
-                        }
+                        on_error(ServeError::Connection {
+                            remote_addr: Some(RemoteAddr::new(remote_addr_for_error)),
+                            error: err,
+                        });
                     }
-                });
+                }));
             }
         }))
     }
@@ -210,53 +355,105 @@ where
 
 /// Serve future with graceful shutdown enabled.
 #[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
-pub struct WithGracefulShutdown<M, S, F> {
-    tcp_listener: TcpListener,
+pub struct WithGracefulShutdown<L, M, S, F, E = TokioExecutor> {
+    listener: L,
     make_service: M,
+    builder: Builder<E>,
+    on_error: OnError,
+    spawner: Spawner,
     signal: F,
+    deadline: Option<Duration>,
     _marker: PhantomData<S>,
 }
 
 #[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
-impl<M, S, F> Debug for WithGracefulShutdown<M, S, F>
+impl<L, M, S, F, E> Debug for WithGracefulShutdown<L, M, S, F, E>
 where
+    L: Debug + 'static,
     M: Debug,
     S: Debug,
     F: Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Self {
-            tcp_listener,
+            listener,
             make_service,
+            builder: _,
+            on_error: _,
+            spawner: _,
             signal,
+            deadline,
             _marker: _,
         } = self;
 
         f.debug_struct("WithGracefulShutdown")
-            .field("tcp_listener", tcp_listener)
+            .field("listener", listener)
             .field("make_service", make_service)
             .field("signal", signal)
+            .field("deadline", deadline)
             .finish()
     }
 }
 
 #[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
-impl<M, S, F> IntoFuture for WithGracefulShutdown<M, S, F>
+impl<L, M, S, F, E> WithGracefulShutdown<L, M, S, F, E>
 where
-    M: for<'a> Service<IncomingStream<'a>, Error = Infallible, Response = S> + Send + 'static,
-    for<'a> <M as Service<IncomingStream<'a>>>::Future: Send,
+    L: Listener,
+{
+    /// Configure the underlying [`hyper_util::server::conn::auto::Builder`]. See
+    /// [`Serve::http_builder`] for details.
+    pub fn http_builder(mut self, f: impl FnOnce(&mut Builder<E>)) -> Self {
+        f(&mut self.builder);
+        self
+    }
+
+    /// Register a callback invoked whenever a connection fails to serve. See
+    /// [`Serve::on_error`] for details.
+    pub fn on_error<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(ServeError) + Send + Sync + 'static,
+    {
+        self.on_error = Arc::new(callback);
+        self
+    }
+
+    /// Set a deadline for graceful shutdown.
+    ///
+    /// By default, once the shutdown signal fires, the returned future waits indefinitely for
+    /// every in-flight connection to close on its own. Setting a `timeout` bounds that wait: once
+    /// the deadline elapses, any connections still open are force-closed — each connection task
+    /// drops its connection as soon as it observes the deadline, rather than continuing to drive
+    /// it to completion — and the returned future completes once that teardown is done.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(timeout);
+        self
+    }
+}
+
+#[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
+impl<L, M, S, F, E> IntoFuture for WithGracefulShutdown<L, M, S, F, E>
+where
+    L: Listener,
+    L::Addr: Debug + Clone,
+    M: for<'a> Service<IncomingStream<'a, L>, Error = Infallible, Response = S> + Send + 'static,
+    for<'a> <M as Service<IncomingStream<'a, L>>>::Future: Send,
     S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
     S::Future: Send,
     F: Future<Output = ()> + Send + 'static,
+    E: hyper::rt::Executor<futures_util::future::BoxFuture<'static, ()>> + Clone + Send + Sync + 'static,
 {
     type Output = io::Result<()>;
     type IntoFuture = private::ServeFuture;
 
     fn into_future(self) -> Self::IntoFuture {
         let Self {
-            tcp_listener,
+            mut listener,
             mut make_service,
+            builder,
+            on_error,
+            spawner,
             signal,
+            deadline,
             _marker: _,
         } = self;
 
@@ -269,29 +466,31 @@ where
         });
 
         let (close_tx, close_rx) = watch::channel(());
+        let (deadline_tx, deadline_rx) = watch::channel(());
+        let deadline_tx = Arc::new(deadline_tx);
 
         private::ServeFuture(Box::pin(async move {
             loop {
-                let (tcp_stream, remote_addr) = tokio::select! {
-                    result = tcp_listener.accept() => {
-                        result?
-                    }
+                let (io, remote_addr) = tokio::select! {
+                    conn = listener.accept() => conn,
                     _ = signal_tx.closed() => {
                         trace!("signal received, not accepting new connections");
                         break;
                     }
                 };
-                let tcp_stream = TokioIo::new(tcp_stream);
+                let io = TokioIo::new(io);
 
-                trace!("connection {remote_addr} accepted");
+                trace!("connection {remote_addr:?} accepted");
 
                 poll_fn(|cx| make_service.poll_ready(cx))
                     .await
                     .unwrap_or_else(|err| match err {});
 
+                let remote_addr_for_task = remote_addr.clone();
+
                 let tower_service = make_service
                     .call(IncomingStream {
-                        tcp_stream: &tcp_stream,
+                        io: &io,
                         remote_addr,
                     })
                     .await
@@ -302,12 +501,16 @@ where
                 };
 
                 let signal_tx = Arc::clone(&signal_tx);
+                let deadline_tx = Arc::clone(&deadline_tx);
 
                 let close_rx = close_rx.clone();
+                let builder = builder.clone();
+                let on_error = Arc::clone(&on_error);
+
+                spawner(Box::pin(async move {
+                    let remote_addr = remote_addr_for_task;
 
-                tokio::spawn(async move {
-                    let builder = Builder::new(TokioExecutor::new());
-                    let conn = builder.serve_connection_with_upgrades(tcp_stream, hyper_service);
+                    let conn = builder.serve_connection_with_upgrades(io, hyper_service);
                     let mut conn = pin!(conn);
 
                     let mut signal_closed = pin!(signal_tx.closed().fuse());
@@ -315,8 +518,11 @@ where
                     loop {
                         tokio::select! {
                             result = conn.as_mut() => {
-                                if let Err(_err) = result {
-                                    trace!("failed to serve connection: {_err:#}");
+                                if let Err(err) = result {
+                                    on_error(ServeError::Connection {
+                                        remote_addr: Some(RemoteAddr::new(remote_addr.clone())),
+                                        error: err,
+                                    });
                                 }
                                 break;
                             }
@@ -324,23 +530,47 @@ where
                                 trace!("signal received in task, starting graceful shutdown");
                                 conn.as_mut().graceful_shutdown();
                             }
+                            _ = deadline_tx.closed() => {
+                                warn!(
+                                    "shutdown deadline elapsed, force-closing connection {remote_addr:?}"
+                                );
+                                break;
+                            }
                         }
                     }
 
-                    trace!("connection {remote_addr} closed");
+                    trace!("connection {remote_addr:?} closed");
 
                     drop(close_rx);
-                });
+                }));
             }
 
             drop(close_rx);
-            drop(tcp_listener);
+            drop(listener);
 
             trace!(
                 "waiting for {} task(s) to finish",
                 close_tx.receiver_count()
             );
-            close_tx.closed().await;
+            match deadline {
+                Some(deadline) => {
+                    tokio::select! {
+                        () = close_tx.closed() => {}
+                        () = tokio::time::sleep(deadline) => {
+                            warn!(
+                                "graceful shutdown timeout elapsed with {} connection(s) still open, \
+                                 force-closing them",
+                                close_tx.receiver_count()
+                            );
+                            // Tell every still-running connection task to drop its connection
+                            // immediately instead of waiting for it to finish on its own.
+                            drop(deadline_rx);
+                            close_tx.closed().await;
+                        }
+                    }
+                }
+                None => close_tx.closed().await,
+            }
 
             Ok(())
         }))
@@ -421,21 +651,364 @@ where
 /// Used with [`serve`] and [`IntoMakeServiceWithConnectInfo`].
 ///
 /// [`IntoMakeServiceWithConnectInfo`]: crate::extract::connect_info::IntoMakeServiceWithConnectInfo
-#[derive(Debug)]
-pub struct IncomingStream<'a> {
-    tcp_stream: &'a TokioIo<TcpStream>,
-    remote_addr: SocketAddr,
+pub struct IncomingStream<'a, L>
+where
+    L: Listener,
+{
+    io: &'a TokioIo<L::Io>,
+    remote_addr: L::Addr,
 }
 
-impl IncomingStream<'_> {
-    /// Returns the local address that this stream is bound to.
-    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
-        self.tcp_stream.inner().local_addr()
+impl<L> IncomingStream<'_, L>
+where
+    L: Listener,
+{
+    /// Returns the underlying IO stream.
+    ///
+    /// This is whatever `L::Io` is for the [`Listener`] impl in use, e.g. [`TcpStream`] for
+    /// [`TcpListener`] or [`UnixStream`] for [`UnixListener`] — use it to reach
+    /// transport-specific methods such as `TcpStream::local_addr`.
+    pub fn io(&self) -> &L::Io {
+        self.io.inner()
     }
 
     /// Returns the remote address that this stream is bound to.
-    pub fn remote_addr(&self) -> SocketAddr {
-        self.remote_addr
+    pub fn remote_addr(&self) -> L::Addr
+    where
+        L::Addr: Clone,
+    {
+        self.remote_addr.clone()
+    }
+}
+
+impl<L> Debug for IncomingStream<'_, L>
+where
+    L: Listener,
+    L::Io: Debug,
+    L::Addr: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IncomingStream")
+            .field("io", &self.io)
+            .field("remote_addr", &self.remote_addr)
+            .finish()
+    }
+}
+
+/// Types that can listen for incoming connections.
+///
+/// This is implemented for [`tokio::net::TcpListener`] and [`tokio::net::UnixListener`], and is
+/// the foundation [`serve`] is built on so that serving isn't hardwired to TCP. Implement it for
+/// your own type to serve over an already-accepted socket pair, a custom transport, or anything
+/// else that can hand back an [`AsyncRead`] + [`AsyncWrite`] stream and a peer address.
+///
+/// Modeled after hyper's `Accept`.
+pub trait Listener: Send + 'static {
+    /// The listener's IO type.
+    type Io: AsyncRead + AsyncWrite + Unpin + Send + 'static;
+
+    /// The listener's address type.
+    type Addr: Send;
+
+    /// Accept a new incoming connection from this listener.
+    ///
+    /// This is infallible by design: `serve`'s accept loop runs for the lifetime of the server,
+    /// so an impl should retry transient errors internally rather than surface them. The built-in
+    /// [`TcpListener`] and [`UnixListener`] impls do this: per-connection errors (refused,
+    /// aborted, reset) are retried right away without logging, and any other error is logged at
+    /// `error` level via [`tracing`] and retried after one second; as a result there's no
+    /// `ServeError` variant for accept failures.
+    fn accept(&mut self) -> impl Future<Output = (Self::Io, Self::Addr)> + Send;
+
+    /// Returns the local address that this listener is bound to.
+    fn local_addr(&self) -> io::Result<Self::Addr>;
+}
+
+impl Listener for TcpListener {
+    type Io = TcpStream;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        loop {
+            match Self::accept(self).await {
+                Ok(tup) => return tup,
+                Err(e) => handle_accept_error(e).await,
+            }
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        Self::local_addr(self)
+    }
+}
+
+#[cfg(unix)]
+impl Listener for UnixListener {
+    type Io = UnixStream;
+    type Addr = tokio::net::unix::SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        loop {
+            match Self::accept(self).await {
+                Ok(tup) => return tup,
+                Err(e) => handle_accept_error(e).await,
+            }
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        Self::local_addr(self)
+    }
+}
+
+#[cfg(unix)]
+impl IncomingStream<'_, UnixListener> {
+    /// Returns the process ID, the user ID and the group ID of the peer, on Unix platforms that
+    /// support this information.
+    pub fn peer_cred(&self) -> io::Result<UCred> {
+        self.io.inner().peer_cred()
+    }
+}
+
+// Copied from hyper 1.0 `TcpListener::accept` docs: tolerate transient accept errors like
+// running out of file descriptors instead of tearing down the whole accept loop.
+async fn handle_accept_error(e: io::Error) {
+    if is_connection_error(&e) {
+        return;
+    }
+
+    // [From `hyper::Server`]
+    //
+    // > A possible scenario is that the process has hit the max open files
+    // > allowed, and so trying to accept a new connection will fail with
+    // > `EMFILE`. In some cases, it's preferable to just wait for some time, if
+    // > the application will likely close some files (or connections), and try
+    // > to accept the connection again. If this option is `true`, the error
+    // > will be logged at the `error` level, since it is still a big deal,
+    // > and then the listener will sleep for 1 second.
+    error!("accept error: {e}");
+    tokio::time::sleep(Duration::from_secs(1)).await;
+}
+
+fn is_connection_error(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionAborted | io::ErrorKind::ConnectionReset
+    )
+}
+
+/// An error encountered while serving an already-accepted connection.
+///
+/// Passed to the callback registered with [`Serve::on_error`] or
+/// [`WithGracefulShutdown::on_error`]. Accept errors and TLS handshake errors are not reported
+/// through this type.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ServeError {
+    /// Serving an already-accepted connection failed.
+    Connection {
+        /// The remote address of the connection, if the listener reported one.
+        ///
+        /// Carries the concrete `L::Addr` the [`Listener`] produced (e.g. [`SocketAddr`] for the
+        /// built-in [`TcpListener`]); use [`RemoteAddr::downcast_ref`] to recover it.
+        remote_addr: Option<RemoteAddr>,
+        /// The underlying error.
+        error: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+impl std::fmt::Display for ServeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connection { remote_addr, error } => match remote_addr {
+                Some(remote_addr) => write!(f, "error serving connection {remote_addr:?}: {error}"),
+                None => write!(f, "error serving connection: {error}"),
+            },
+        }
+    }
+}
+
+/// A type-erased copy of whatever [`Listener::Addr`] a connection's listener reported, carried
+/// by [`ServeError::Connection`].
+///
+/// `ServeError` isn't generic over the listener, so the address can't be stored as `L::Addr`
+/// directly; this keeps the concrete value (instead of eagerly formatting it to a `String`) so
+/// callers that know the listener type in use can recover it with [`Self::downcast_ref`].
+pub struct RemoteAddr(Box<dyn DebugAny>);
+
+impl RemoteAddr {
+    fn new<A: std::fmt::Debug + Send + Sync + 'static>(addr: A) -> Self {
+        Self(Box::new(addr))
+    }
+
+    /// Recovers the concrete address type, e.g. [`SocketAddr`] for the built-in [`TcpListener`].
+    pub fn downcast_ref<A: 'static>(&self) -> Option<&A> {
+        self.0.as_any().downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for RemoteAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_debug(f)
+    }
+}
+
+trait DebugAny: std::any::Any + Send + Sync {
+    fn as_any(&self) -> &dyn std::any::Any;
+    fn fmt_debug(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+}
+
+impl<A: std::any::Any + std::fmt::Debug + Send + Sync> DebugAny for A {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn fmt_debug(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ServeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Connection { error, .. } => Some(&**error),
+        }
+    }
+}
+
+type OnError = Arc<dyn Fn(ServeError) + Send + Sync>;
+
+/// The default [`Serve::on_error`]/[`WithGracefulShutdown::on_error`] callback: log the error at
+/// `trace` level.
+fn default_on_error(err: ServeError) {
+    trace!("{err}");
+}
+
+type Spawner = Arc<dyn Fn(futures_util::future::BoxFuture<'static, ()>) + Send + Sync>;
+
+/// The default spawner used by [`serve`]: hand the future to [`tokio::spawn`], same as before
+/// [`serve_with_executor`] existed.
+fn tokio_spawner(fut: futures_util::future::BoxFuture<'static, ()>) {
+    tokio::spawn(fut);
+}
+
+/// TLS termination via [`tokio_rustls`], available when the `tls-rustls` feature is enabled.
+#[cfg(feature = "tls-rustls")]
+pub mod tls_rustls {
+    use std::{convert::Infallible, io, net::SocketAddr, sync::Arc};
+
+    use axum_core::{extract::Request, response::Response};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_rustls::{
+        rustls::{pki_types::CertificateDer, ServerConfig},
+        server::TlsStream,
+        TlsAcceptor,
+    };
+    use tower_service::Service;
+
+    use super::{serve, handle_accept_error, IncomingStream, Listener, Serve};
+
+    /// Serve the service with TLS termination, using the supplied rustls [`ServerConfig`] to
+    /// perform the handshake.
+    ///
+    /// This wraps `tcp_listener` in a [`RustlsListener`] and hands it to [`serve`](super::serve),
+    /// so the returned [`Serve`] supports the same [`http_builder`](Serve::http_builder),
+    /// [`on_error`](Serve::on_error) and [`with_graceful_shutdown`](Serve::with_graceful_shutdown)
+    /// configuration as plain TCP serving (use [`serve_with_executor`](super::serve_with_executor)
+    /// directly with a [`RustlsListener`] if you also need a custom executor). The negotiated ALPN
+    /// protocol and the client's certificate chain (if presented) are available through
+    /// [`IncomingStream::alpn_protocol`] and [`IncomingStream::peer_certificates`].
+    ///
+    /// Handshake failures are logged at `trace` level and simply drop that connection; they
+    /// don't bring down the server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axum::{serve::tls_rustls::serve_tls, Router, routing::get};
+    /// use std::sync::Arc;
+    ///
+    /// # async fn rustls_config() -> Arc<tokio_rustls::rustls::ServerConfig> {
+    /// #     unimplemented!()
+    /// # }
+    /// # async {
+    /// let router = Router::new().route("/", get(|| async { "Hello, World!" }));
+    ///
+    /// let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+    /// let rustls_config = rustls_config().await;
+    /// serve_tls(listener, rustls_config, router).await.unwrap();
+    /// # };
+    /// ```
+    pub fn serve_tls<M, S>(
+        tcp_listener: TcpListener,
+        rustls_config: Arc<ServerConfig>,
+        make_service: M,
+    ) -> Serve<RustlsListener, M, S>
+    where
+        M: for<'a> Service<IncomingStream<'a, RustlsListener>, Error = Infallible, Response = S>,
+        S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
+        S::Future: Send,
+    {
+        serve(RustlsListener::new(tcp_listener, rustls_config), make_service)
+    }
+
+    /// A [`Listener`] that accepts TCP connections and terminates TLS on each one using
+    /// [`tokio_rustls`], before handing the resulting stream off to the accept loop.
+    ///
+    /// Used by [`serve_tls`]; construct one directly if you need to pass it to
+    /// [`serve_with_executor`](super::serve_with_executor) instead.
+    pub struct RustlsListener {
+        tcp_listener: TcpListener,
+        tls_acceptor: TlsAcceptor,
+    }
+
+    impl RustlsListener {
+        /// Wrap `tcp_listener`, performing a TLS handshake using `rustls_config` for every
+        /// accepted connection.
+        pub fn new(tcp_listener: TcpListener, rustls_config: Arc<ServerConfig>) -> Self {
+            Self {
+                tcp_listener,
+                tls_acceptor: TlsAcceptor::from(rustls_config),
+            }
+        }
+    }
+
+    impl Listener for RustlsListener {
+        type Io = TlsStream<TcpStream>;
+        type Addr = SocketAddr;
+
+        async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+            loop {
+                let (tcp_stream, remote_addr) = loop {
+                    match self.tcp_listener.accept().await {
+                        Ok(accepted) => break accepted,
+                        Err(e) => handle_accept_error(e).await,
+                    }
+                };
+
+                match self.tls_acceptor.accept(tcp_stream).await {
+                    Ok(tls_stream) => return (tls_stream, remote_addr),
+                    Err(err) => trace!("tls handshake error from {remote_addr}: {err}"),
+                }
+            }
+        }
+
+        fn local_addr(&self) -> io::Result<Self::Addr> {
+            self.tcp_listener.local_addr()
+        }
+    }
+
+    impl IncomingStream<'_, RustlsListener> {
+        /// Returns the ALPN protocol negotiated during the TLS handshake, if any.
+        pub fn alpn_protocol(&self) -> Option<&[u8]> {
+            self.io().get_ref().1.alpn_protocol()
+        }
+
+        /// Returns the certificate chain presented by the peer during the TLS handshake, if the
+        /// client authenticated with one.
+        pub fn peer_certificates(&self) -> Option<&[CertificateDer<'static>]> {
+            self.io().get_ref().1.peer_certificates()
+        }
     }
 }
 